@@ -6,8 +6,17 @@ use serde_json::Value;
 use std::collections::HashMap;
 use std::fs::File;
 use std::io::Read;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::process::Command;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::sync_channel;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// Name the row template is registered under so it only has to be parsed
+/// once, in `CsvProcessor::new`, and then looked up by name once per input
+/// before its rows are rendered.
+const TEMPLATE_NAME: &str = "row_command";
 
 #[derive(Parser)]
 #[command(name = "csvargs")]
@@ -19,6 +28,70 @@ pub struct Args {
     #[arg(long = "no-header", help = "CSV files do NOT have header row")]
     pub no_header: bool,
 
+    #[arg(
+        long = "jobs",
+        short = 'j',
+        default_value_t = 1,
+        help = "Run up to N commands concurrently (0 = use all logical CPUs); ignored by --output-csv, which always writes rows in order on a single thread"
+    )]
+    pub jobs: usize,
+
+    #[arg(
+        long = "delimiter",
+        help = "Field delimiter character, e.g. ';' or '\\t' (default ',')"
+    )]
+    pub delimiter: Option<String>,
+
+    #[arg(long = "quote", help = "Quote character (default '\"')")]
+    pub quote: Option<String>,
+
+    #[arg(long = "no-quoting", help = "Disable CSV quoting entirely")]
+    pub no_quoting: bool,
+
+    #[arg(
+        long = "comment",
+        help = "Lines starting with this character are ignored"
+    )]
+    pub comment: Option<String>,
+
+    #[arg(
+        long = "trim",
+        help = "Trim leading/trailing whitespace from headers and fields"
+    )]
+    pub trim: bool,
+
+    #[arg(
+        long = "flexible",
+        help = "Allow rows with a different number of fields than the header"
+    )]
+    pub flexible: bool,
+
+    #[arg(
+        long = "infer-types",
+        help = "Parse column values as integers, floats, or booleans where possible"
+    )]
+    pub infer_types: bool,
+
+    #[arg(
+        long = "dry-run",
+        help = "Print each row's rendered command without executing it"
+    )]
+    pub dry_run: bool,
+
+    #[arg(
+        long = "output-csv",
+        value_name = "FILE",
+        help = "Write each row plus a column of captured command stdout to FILE instead of printing output (always single-threaded, regardless of --jobs)"
+    )]
+    pub output_csv: Option<String>,
+
+    #[arg(
+        long = "continue-on-error",
+        visible_alias = "keep-going",
+        help = "Keep processing rows after a failure instead of aborting; print a summary and exit non-zero if any row failed"
+    )]
+    pub continue_on_error: bool,
+
     #[arg(value_name = "FILES", help = "CSV files to process")]
     pub files: Vec<String>,
 }
@@ -26,23 +99,94 @@ pub struct Args {
 #[derive(Debug)]
 pub struct CsvProcessor {
     env: Environment<'static>,
-    template_str: String,
     has_header: bool,
+    jobs: usize,
+    delimiter: u8,
+    quote: u8,
+    no_quoting: bool,
+    comment: Option<u8>,
+    trim: bool,
+    flexible: bool,
+    infer_types: bool,
+    dry_run: bool,
+    output_csv: Option<PathBuf>,
+    continue_on_error: bool,
 }
 
 impl CsvProcessor {
-    pub fn new(template_str: &str, has_header: bool) -> Result<Self> {
-        let env = Environment::new();
-        env.template_from_str(template_str)
+    pub fn new(args: &Args) -> Result<Self> {
+        let mut env = Environment::new();
+        // `add_template` needs a `&'static str`, but the template comes from a
+        // runtime `String` (the CLI argument). `add_template_owned` would
+        // sidestep that, but it sits behind minijinja's non-default `loader`
+        // feature, which this crate doesn't enable. Leak the one
+        // (per-process) owned template string instead so the default feature
+        // set is enough.
+        let template: &'static str = Box::leak(args.template.clone().into_boxed_str());
+        env.add_template(TEMPLATE_NAME, template)
             .with_context(|| "Failed to parse template")?;
-        
+
+        let jobs = if args.jobs == 0 {
+            thread::available_parallelism()
+                .map(|n| n.get())
+                .unwrap_or(1)
+        } else {
+            args.jobs
+        };
+
+        let delimiter = match &args.delimiter {
+            Some(d) => parse_dialect_char("--delimiter", d)?,
+            None => b',',
+        };
+        let quote = match &args.quote {
+            Some(q) => parse_dialect_char("--quote", q)?,
+            None => b'"',
+        };
+        let comment = args
+            .comment
+            .as_deref()
+            .map(|c| parse_dialect_char("--comment", c))
+            .transpose()?;
+
         Ok(Self {
             env,
-            template_str: template_str.to_string(),
-            has_header,
+            has_header: !args.no_header,
+            jobs,
+            delimiter,
+            quote,
+            no_quoting: args.no_quoting,
+            comment,
+            trim: args.trim,
+            flexible: args.flexible,
+            infer_types: args.infer_types,
+            dry_run: args.dry_run,
+            output_csv: args.output_csv.as_ref().map(PathBuf::from),
+            continue_on_error: args.continue_on_error,
         })
     }
 
+    /// Apply the configured CSV dialect to a fresh reader.
+    fn build_reader<R: Read>(&self, reader: R) -> csv::Reader<R> {
+        let mut builder = ReaderBuilder::new();
+        builder
+            .has_headers(self.has_header)
+            .delimiter(self.delimiter)
+            .quote(self.quote)
+            .quoting(!self.no_quoting)
+            .flexible(self.flexible)
+            .trim(if self.trim {
+                csv::Trim::All
+            } else {
+                csv::Trim::None
+            });
+
+        if let Some(comment) = self.comment {
+            builder.comment(Some(comment));
+        }
+
+        builder.from_reader(reader)
+    }
+
     pub fn process_file<P: AsRef<Path>>(&self, file_path: P) -> Result<()> {
         let file_path = file_path.as_ref();
         let file = File::open(file_path)
@@ -52,9 +196,21 @@ impl CsvProcessor {
     }
 
     pub fn process_reader<R: Read>(&self, reader: R) -> Result<()> {
-        let mut csv_reader = ReaderBuilder::new()
-            .has_headers(self.has_header)
-            .from_reader(reader);
+        if self.output_csv.is_some() {
+            return self.process_reader_to_csv(reader);
+        }
+
+        // Dry runs have no real work to parallelize, so always process them
+        // on the main thread.
+        if self.dry_run || self.jobs <= 1 {
+            self.process_reader_serial(reader)
+        } else {
+            self.process_reader_parallel(reader)
+        }
+    }
+
+    fn process_reader_serial<R: Read>(&self, reader: R) -> Result<()> {
+        let mut csv_reader = self.build_reader(reader);
 
         let headers = if self.has_header {
             Some(csv_reader.headers()?.clone())
@@ -62,26 +218,431 @@ impl CsvProcessor {
             None
         };
 
-        for (row_index, result) in csv_reader.records().enumerate() {
-            let record = result
-                .with_context(|| format!("Failed to read row {}", row_index))?;
-            
-            let row_data = match &headers {
-                Some(h) => create_named_context(h, &record),
-                None => create_indexed_context(&record),
+        let template = self
+            .env
+            .get_template(TEMPLATE_NAME)
+            .with_context(|| "Failed to load compiled template")?;
+
+        // Built once and reused for every row: values are overwritten in
+        // place instead of allocating a fresh HashMap per record.
+        let mut row_data: HashMap<String, Value> = match &headers {
+            Some(h) => named_context_skeleton(h),
+            None => HashMap::new(),
+        };
+        let mut record = csv::StringRecord::new();
+        let mut row_index = 0usize;
+        let mut failures: Vec<RowFailure> = Vec::new();
+        let mut index_keys: Vec<String> = Vec::new();
+
+        loop {
+            let has_record = match csv_reader.read_record(&mut record) {
+                Ok(has_record) => has_record,
+                Err(e) if self.continue_on_error => {
+                    failures.push(RowFailure::new(
+                        row_index,
+                        None,
+                        anyhow::Error::new(e).context(format!("Failed to read row {}", row_index)),
+                    ));
+                    row_index += 1;
+                    continue;
+                }
+                Err(e) => {
+                    return Err(e).with_context(|| format!("Failed to read row {}", row_index))
+                }
+            };
+            if !has_record {
+                break;
+            }
+
+            match &headers {
+                Some(h) => update_named_context(&mut row_data, h, &record, self.infer_types),
+                None => update_indexed_context(
+                    &mut row_data,
+                    &record,
+                    self.infer_types,
+                    &mut index_keys,
+                    self.flexible,
+                ),
+            }
+
+            let rendered = match template.render(context! { row => &row_data }) {
+                Ok(rendered) => rendered,
+                Err(e) if self.continue_on_error => {
+                    failures.push(RowFailure::new(
+                        row_index,
+                        None,
+                        anyhow::Error::new(e)
+                            .context(format!("Failed to render template for row {}", row_index)),
+                    ));
+                    row_index += 1;
+                    continue;
+                }
+                Err(e) => {
+                    return Err(e).with_context(|| {
+                        format!("Failed to render template for row {}", row_index)
+                    })
+                }
             };
 
-            let template = self.env.template_from_str(&self.template_str)
-                .with_context(|| "Failed to parse template")?;
-            let rendered = template.render(context! { row => row_data })
-                .with_context(|| format!("Failed to render template for row {}", row_index))?;
+            if self.dry_run {
+                println!("Would execute for row {}: {}", row_index, rendered);
+            } else {
+                let result = execute_command(&rendered, row_index)
+                    .with_context(|| format!("Failed to execute command for row {}", row_index));
+                if let Err(e) = result {
+                    if self.continue_on_error {
+                        failures.push(RowFailure::new(row_index, Some(rendered), e));
+                    } else {
+                        return Err(e);
+                    }
+                }
+            }
+
+            row_index += 1;
+        }
+
+        finish_with_failures(row_index, failures)
+    }
+
+    /// Same behavior as `process_reader_serial`, but rendered commands are
+    /// handed off to a bounded pool of `self.jobs` worker threads instead of
+    /// being executed inline. Rows are still read and rendered strictly in
+    /// order on the main thread (the minijinja `Template` stays single
+    /// threaded); only `execute_command` runs concurrently. Without
+    /// `--continue-on-error`, the first failing command stops submission of
+    /// further rows, and once every in-flight worker has drained the failure
+    /// with the lowest row index is returned.
+    fn process_reader_parallel<R: Read>(&self, reader: R) -> Result<()> {
+        let mut csv_reader = self.build_reader(reader);
+
+        let headers = if self.has_header {
+            Some(csv_reader.headers()?.clone())
+        } else {
+            None
+        };
+
+        let template = self
+            .env
+            .get_template(TEMPLATE_NAME)
+            .with_context(|| "Failed to load compiled template")?;
+
+        let (tx, rx) = sync_channel::<(usize, String)>(self.jobs * 2);
+        let rx = Arc::new(Mutex::new(rx));
+        let stdout_lock = Arc::new(Mutex::new(()));
+        let stop = Arc::new(AtomicBool::new(false));
+        let failures: Arc<Mutex<Vec<RowFailure>>> = Arc::new(Mutex::new(Vec::new()));
+        let continue_on_error = self.continue_on_error;
+
+        let workers: Vec<_> = (0..self.jobs)
+            .map(|_| {
+                let rx = Arc::clone(&rx);
+                let stdout_lock = Arc::clone(&stdout_lock);
+                let stop = Arc::clone(&stop);
+                let failures = Arc::clone(&failures);
+
+                thread::spawn(move || loop {
+                    let job = rx.lock().unwrap().recv();
+                    let (row_index, command) = match job {
+                        Ok(job) => job,
+                        Err(_) => break,
+                    };
+
+                    if let Err(e) = execute_command_guarded(&command, row_index, &stdout_lock)
+                        .with_context(|| format!("Failed to execute command for row {}", row_index))
+                    {
+                        failures
+                            .lock()
+                            .unwrap()
+                            .push(RowFailure::new(row_index, Some(command), e));
+                        if !continue_on_error {
+                            stop.store(true, Ordering::SeqCst);
+                        }
+                    }
+                })
+            })
+            .collect();
+
+        let mut row_data: HashMap<String, Value> = match &headers {
+            Some(h) => named_context_skeleton(h),
+            None => HashMap::new(),
+        };
+        let mut record = csv::StringRecord::new();
+        let mut row_index = 0usize;
+        let mut read_error: Option<RowFailure> = None;
+        let mut index_keys: Vec<String> = Vec::new();
+
+        while !stop.load(Ordering::SeqCst) {
+            match csv_reader.read_record(&mut record) {
+                Ok(true) => {}
+                Ok(false) => break,
+                Err(e) => {
+                    let e =
+                        anyhow::Error::new(e).context(format!("Failed to read row {}", row_index));
+                    if continue_on_error {
+                        failures
+                            .lock()
+                            .unwrap()
+                            .push(RowFailure::new(row_index, None, e));
+                        row_index += 1;
+                        continue;
+                    }
+                    read_error = Some(RowFailure::new(row_index, None, e));
+                    break;
+                }
+            }
+
+            match &headers {
+                Some(h) => update_named_context(&mut row_data, h, &record, self.infer_types),
+                None => update_indexed_context(
+                    &mut row_data,
+                    &record,
+                    self.infer_types,
+                    &mut index_keys,
+                    self.flexible,
+                ),
+            }
+
+            let rendered = match template.render(context! { row => &row_data }) {
+                Ok(rendered) => rendered,
+                Err(e) => {
+                    let e = anyhow::Error::new(e)
+                        .context(format!("Failed to render template for row {}", row_index));
+                    if continue_on_error {
+                        failures
+                            .lock()
+                            .unwrap()
+                            .push(RowFailure::new(row_index, None, e));
+                        row_index += 1;
+                        continue;
+                    }
+                    read_error = Some(RowFailure::new(row_index, None, e));
+                    break;
+                }
+            };
+
+            if tx.send((row_index, rendered)).is_err() {
+                break;
+            }
+
+            row_index += 1;
+        }
 
-            execute_command(&rendered, row_index)
-                .with_context(|| format!("Failed to execute command for row {}", row_index))?;
+        drop(tx);
+        for worker in workers {
+            let _ = worker.join();
+        }
+
+        let mut failures = Arc::try_unwrap(failures)
+            .expect("all worker threads have been joined")
+            .into_inner()
+            .unwrap();
+        failures.extend(read_error);
+
+        if continue_on_error {
+            return finish_with_failures(row_index, failures);
+        }
+
+        // Without --continue-on-error, preserve the old fail-fast behavior:
+        // surface the earliest row's failure directly rather than printing
+        // the accumulated-failures summary. Several rows may already have
+        // been in flight when `stop` was set, and the main-thread read/render
+        // loop can itself fail on a later row than an in-flight command, so
+        // compare by row index rather than by which happened to occur first.
+        if let Some(failure) = failures.into_iter().min_by_key(|f| f.row_index) {
+            return Err(failure.error);
         }
 
         Ok(())
     }
+
+    fn process_reader_to_csv<R: Read>(&self, reader: R) -> Result<()> {
+        let mut csv_reader = self.build_reader(reader);
+
+        let headers = if self.has_header {
+            Some(csv_reader.headers()?.clone())
+        } else {
+            None
+        };
+
+        let template = self
+            .env
+            .get_template(TEMPLATE_NAME)
+            .with_context(|| "Failed to load compiled template")?;
+
+        let output_path = self
+            .output_csv
+            .as_ref()
+            .expect("process_reader_to_csv called without an output_csv path");
+        let mut csv_writer = csv::WriterBuilder::new()
+            .flexible(self.flexible)
+            .from_path(output_path)
+            .with_context(|| {
+                format!(
+                    "Failed to create output CSV file: {}",
+                    output_path.display()
+                )
+            })?;
+
+        if let Some(h) = &headers {
+            let mut out_headers: Vec<&str> = h.iter().collect();
+            out_headers.push("command_stdout");
+            csv_writer
+                .write_record(&out_headers)
+                .with_context(|| "Failed to write output CSV header")?;
+        }
+
+        let mut row_data = match &headers {
+            Some(h) => named_context_skeleton(h),
+            None => HashMap::new(),
+        };
+        let mut record = csv::StringRecord::new();
+        let mut row_index = 0usize;
+        let mut failures: Vec<RowFailure> = Vec::new();
+        let mut index_keys: Vec<String> = Vec::new();
+        let mut field_count = headers.as_ref().map_or(0, |h| h.len());
+
+        loop {
+            let has_record = match csv_reader.read_record(&mut record) {
+                Ok(has_record) => has_record,
+                Err(e) if self.continue_on_error => {
+                    let out_record = vec![""; field_count + 1];
+                    csv_writer
+                        .write_record(&out_record)
+                        .with_context(|| format!("Failed to write output row {}", row_index))?;
+
+                    failures.push(RowFailure::new(
+                        row_index,
+                        None,
+                        anyhow::Error::new(e).context(format!("Failed to read row {}", row_index)),
+                    ));
+                    row_index += 1;
+                    continue;
+                }
+                Err(e) => {
+                    return Err(e).with_context(|| format!("Failed to read row {}", row_index))
+                }
+            };
+            if !has_record {
+                break;
+            }
+            field_count = record.len();
+
+            match &headers {
+                Some(h) => update_named_context(&mut row_data, h, &record, self.infer_types),
+                None => update_indexed_context(
+                    &mut row_data,
+                    &record,
+                    self.infer_types,
+                    &mut index_keys,
+                    self.flexible,
+                ),
+            }
+
+            let rendered = match template.render(context! { row => &row_data }) {
+                Ok(rendered) => rendered,
+                Err(e) if self.continue_on_error => {
+                    let mut out_record: Vec<&str> = record.iter().collect();
+                    out_record.push("");
+                    csv_writer
+                        .write_record(&out_record)
+                        .with_context(|| format!("Failed to write output row {}", row_index))?;
+
+                    failures.push(RowFailure::new(
+                        row_index,
+                        None,
+                        anyhow::Error::new(e)
+                            .context(format!("Failed to render template for row {}", row_index)),
+                    ));
+                    row_index += 1;
+                    continue;
+                }
+                Err(e) => {
+                    return Err(e).with_context(|| {
+                        format!("Failed to render template for row {}", row_index)
+                    })
+                }
+            };
+
+            let stdout = if self.dry_run {
+                println!("Would execute for row {}: {}", row_index, rendered);
+                String::new()
+            } else {
+                match capture_command(&rendered, row_index)
+                    .with_context(|| format!("Failed to execute command for row {}", row_index))
+                {
+                    Ok(stdout) => stdout,
+                    Err(e) if self.continue_on_error => {
+                        failures.push(RowFailure::new(row_index, Some(rendered), e));
+                        String::new()
+                    }
+                    Err(e) => return Err(e),
+                }
+            };
+
+            let mut out_record: Vec<&str> = record.iter().collect();
+            out_record.push(&stdout);
+            csv_writer
+                .write_record(&out_record)
+                .with_context(|| format!("Failed to write output row {}", row_index))?;
+
+            row_index += 1;
+        }
+
+        csv_writer
+            .flush()
+            .with_context(|| "Failed to flush output CSV file")?;
+
+        finish_with_failures(row_index, failures)
+    }
+}
+
+/// One row's read/render/execute failure captured during a
+/// `--continue-on-error` run so the batch can keep going and every failure
+/// can still be reported once it finishes.
+#[derive(Debug)]
+struct RowFailure {
+    row_index: usize,
+    command: Option<String>,
+    error: anyhow::Error,
+}
+
+impl RowFailure {
+    fn new(row_index: usize, command: Option<String>, error: anyhow::Error) -> Self {
+        Self {
+            row_index,
+            command,
+            error,
+        }
+    }
+}
+
+/// Print a summary of a `--continue-on-error` run's failures and turn them
+/// into an overall error so the process exits non-zero, after every row has
+/// already been attempted. A no-op when `failures` is empty. The returned
+/// error carries the same per-row detail that gets printed, so library
+/// callers of `process_reader` don't have to scrape stderr to find out which
+/// rows failed.
+fn finish_with_failures(total_rows: usize, failures: Vec<RowFailure>) -> Result<()> {
+    if failures.is_empty() {
+        return Ok(());
+    }
+
+    let mut message = format!("{} of {} row(s) failed:", failures.len(), total_rows);
+    for failure in &failures {
+        match &failure.command {
+            Some(command) => {
+                message += &format!(
+                    "\n  row {}: {} -> {:#}",
+                    failure.row_index, command, failure.error
+                )
+            }
+            None => message += &format!("\n  row {}: {:#}", failure.row_index, failure.error),
+        }
+    }
+
+    eprintln!("{}", message);
+
+    Err(anyhow::anyhow!(message))
 }
 
 fn main() -> Result<()> {
@@ -91,99 +652,410 @@ fn main() -> Result<()> {
         anyhow::bail!("At least one CSV file must be provided");
     }
 
-    let processor = CsvProcessor::new(&args.template, !args.no_header)?;
-    
+    let processor = CsvProcessor::new(&args)?;
+
     for file_path in &args.files {
-        processor.process_file(file_path)
+        processor
+            .process_file(file_path)
             .with_context(|| format!("Failed to process file: {}", file_path))?;
     }
 
     Ok(())
 }
 
-fn create_named_context(headers: &csv::StringRecord, record: &csv::StringRecord) -> HashMap<String, Value> {
-    headers.iter()
-        .enumerate()
-        .map(|(i, header)| {
-            let value = record.get(i).unwrap_or("").to_string();
-            (header.to_string(), Value::String(value))
-        })
-        .collect()
+/// Parse a single-character dialect option (`--delimiter`, `--quote`,
+/// `--comment`), special-casing the common `\t` escape since shells can't
+/// easily pass a literal tab on the command line.
+fn parse_dialect_char(flag_name: &str, value: &str) -> Result<u8> {
+    let ch = if value == "\\t" {
+        '\t'
+    } else {
+        let mut chars = value.chars();
+        let ch = chars
+            .next()
+            .with_context(|| format!("{} requires a single character", flag_name))?;
+        if chars.next().is_some() {
+            anyhow::bail!("{} must be a single character, got {:?}", flag_name, value);
+        }
+        ch
+    };
+
+    if !ch.is_ascii() {
+        anyhow::bail!("{} must be an ASCII character, got {:?}", flag_name, value);
+    }
+
+    Ok(ch as u8)
 }
 
-fn create_indexed_context(record: &csv::StringRecord) -> HashMap<String, Value> {
-    record.iter()
-        .enumerate()
-        .map(|(i, field)| (i.to_string(), Value::String(field.to_string())))
+/// Build the initial context map for a header-bearing CSV: one entry per
+/// header, pre-allocated so every row after the first only overwrites
+/// existing values.
+fn named_context_skeleton(headers: &csv::StringRecord) -> HashMap<String, Value> {
+    headers
+        .iter()
+        .map(|header| (header.to_string(), Value::String(String::new())))
         .collect()
 }
 
-fn execute_command(command: &str, row_index: usize) -> Result<()> {
-    println!("Executing for row {}: {}", row_index, command);
-    
-    let output = if cfg!(target_os = "windows") {
-        Command::new("cmd")
-            .args(["/C", command])
-            .output()
+/// Overwrite `ctx` in place with `record`'s fields keyed by `headers`,
+/// reusing each existing `String` buffer rather than allocating a new one.
+/// With `infer_types`, each value is rebuilt from `infer_value` instead.
+fn update_named_context(
+    ctx: &mut HashMap<String, Value>,
+    headers: &csv::StringRecord,
+    record: &csv::StringRecord,
+    infer_types: bool,
+) {
+    for (i, header) in headers.iter().enumerate() {
+        let value = record.get(i).unwrap_or("");
+        if infer_types {
+            match ctx.get_mut(header) {
+                Some(slot) => *slot = infer_value(value),
+                None => {
+                    ctx.insert(header.to_string(), infer_value(value));
+                }
+            }
+            continue;
+        }
+        match ctx.get_mut(header) {
+            Some(Value::String(s)) => {
+                s.clear();
+                s.push_str(value);
+            }
+            _ => {
+                ctx.insert(header.to_string(), Value::String(value.to_string()));
+            }
+        }
+    }
+}
+
+/// Overwrite `ctx` in place with `record`'s fields keyed by column index.
+/// `index_keys` caches the `String` form of each index so it's allocated
+/// once per column, not once per field per row.
+fn update_indexed_context(
+    ctx: &mut HashMap<String, Value>,
+    record: &csv::StringRecord,
+    infer_types: bool,
+    index_keys: &mut Vec<String>,
+    flexible: bool,
+) {
+    while index_keys.len() < record.len() {
+        index_keys.push(index_keys.len().to_string());
+    }
+
+    for (i, field) in record.iter().enumerate() {
+        let key = &index_keys[i];
+        if infer_types {
+            match ctx.get_mut(key.as_str()) {
+                Some(slot) => *slot = infer_value(field),
+                None => {
+                    ctx.insert(key.clone(), infer_value(field));
+                }
+            }
+            continue;
+        }
+        match ctx.get_mut(key.as_str()) {
+            Some(Value::String(s)) => {
+                s.clear();
+                s.push_str(field);
+            }
+            _ => {
+                ctx.insert(key.clone(), Value::String(field.to_string()));
+            }
+        }
+    }
+
+    // Without `--flexible`, csv enforces a constant field count, so a
+    // shorter row is never possible and this scan would only add cost to
+    // every row for nothing. With `--flexible`, a row can be shorter than an
+    // earlier one; drop any indices beyond this record's width so they don't
+    // leak a previous row's value into this one.
+    if flexible && record.len() < index_keys.len() {
+        let width = record.len();
+        ctx.retain(|key, _| key.parse::<usize>().is_ok_and(|i| i < width));
+    }
+}
+
+/// Parse a field as an integer, then a float, then a boolean, falling back
+/// to the original string. Empty fields stay empty strings rather than
+/// being coerced to `0` so absent values don't silently become numbers.
+fn infer_value(field: &str) -> Value {
+    if field.is_empty() {
+        return Value::String(String::new());
+    }
+
+    if !has_leading_zero(field) {
+        if let Ok(i) = field.parse::<i64>() {
+            return Value::Number(i.into());
+        }
+
+        if let Ok(f) = field.parse::<f64>() {
+            if let Some(number) = serde_json::Number::from_f64(f) {
+                return Value::Number(number);
+            }
+        }
+    }
+
+    match field {
+        "true" => Value::Bool(true),
+        "false" => Value::Bool(false),
+        _ => Value::String(field.to_string()),
+    }
+}
+
+/// True for fields whose digits start with a non-significant leading zero
+/// (e.g. `"007"`, `"-007"`, `"01.5"`), which would lose information if parsed and
+/// re-rendered as a number. A lone `"0"` is not considered leading-zero.
+fn has_leading_zero(field: &str) -> bool {
+    let digits = field.strip_prefix(['+', '-']).unwrap_or(field);
+    let mut chars = digits.chars();
+    chars.next() == Some('0') && matches!(chars.next(), Some(c) if c.is_ascii_digit())
+}
+
+/// Run `command` through the platform shell and return its output.
+fn run_command(command: &str) -> Result<std::process::Output> {
+    if cfg!(target_os = "windows") {
+        Command::new("cmd").args(["/C", command]).output()
     } else {
-        Command::new("sh")
-            .args(["-c", command])
-            .output()
+        Command::new("sh").args(["-c", command]).output()
     }
-    .with_context(|| "Failed to execute command")?;
+    .with_context(|| "Failed to execute command")
+}
 
+/// Return an error carrying the exit status and stderr if `output` reports
+/// a non-zero exit.
+fn check_command_success(output: &std::process::Output) -> Result<()> {
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
         anyhow::bail!("Command failed with status {}: {}", output.status, stderr);
     }
 
+    Ok(())
+}
+
+fn execute_command(command: &str, row_index: usize) -> Result<()> {
+    println!("Executing for row {}: {}", row_index, command);
+
+    let output = run_command(command)?;
+    check_command_success(&output)?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    if !stdout.trim().is_empty() {
+        println!("{}", stdout);
+    }
+
+    Ok(())
+}
+
+/// Same as `execute_command`, but holds `stdout_lock` while printing so that
+/// output from concurrent workers doesn't interleave mid-line.
+fn execute_command_guarded(command: &str, row_index: usize, stdout_lock: &Mutex<()>) -> Result<()> {
+    {
+        let _guard = stdout_lock.lock().unwrap();
+        println!("Executing for row {}: {}", row_index, command);
+    }
+
+    let output = run_command(command)?;
+    check_command_success(&output)?;
+
     let stdout = String::from_utf8_lossy(&output.stdout);
     if !stdout.trim().is_empty() {
+        let _guard = stdout_lock.lock().unwrap();
         println!("{}", stdout);
     }
 
     Ok(())
 }
 
+/// Run `command` and return its captured stdout (trailing whitespace
+/// stripped) instead of printing it, for `--output-csv` enrichment.
+fn capture_command(command: &str, row_index: usize) -> Result<String> {
+    println!("Executing for row {}: {}", row_index, command);
+
+    let output = run_command(command)?;
+    check_command_success(&output)?;
+
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .trim_end()
+        .to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use std::io::Cursor;
-    use tempfile::NamedTempFile;
     use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    /// Build an `Args` with sane defaults for the given template/header/jobs
+    /// combination, so individual tests don't have to spell out every
+    /// dialect field.
+    fn test_args(template: &str, has_header: bool, jobs: usize) -> Args {
+        Args {
+            template: template.to_string(),
+            no_header: !has_header,
+            jobs,
+            delimiter: None,
+            quote: None,
+            no_quoting: false,
+            comment: None,
+            trim: false,
+            flexible: false,
+            infer_types: false,
+            dry_run: false,
+            output_csv: None,
+            continue_on_error: false,
+            files: Vec::new(),
+        }
+    }
 
     #[test]
-    fn test_create_named_context() {
+    fn test_named_context_skeleton_and_update() {
         let headers = csv::StringRecord::from(vec!["name", "age", "city"]);
+        let mut context = named_context_skeleton(&headers);
+
         let record = csv::StringRecord::from(vec!["Alice", "25", "NYC"]);
-        
-        let context = create_named_context(&headers, &record);
-        
-        assert_eq!(context.get("name"), Some(&Value::String("Alice".to_string())));
+        update_named_context(&mut context, &headers, &record, false);
+
+        assert_eq!(
+            context.get("name"),
+            Some(&Value::String("Alice".to_string()))
+        );
         assert_eq!(context.get("age"), Some(&Value::String("25".to_string())));
         assert_eq!(context.get("city"), Some(&Value::String("NYC".to_string())));
+
+        // The same map is reused for the next row; values overwrite in place.
+        let record = csv::StringRecord::from(vec!["Bob", "30", "LA"]);
+        update_named_context(&mut context, &headers, &record, false);
+
+        assert_eq!(context.get("name"), Some(&Value::String("Bob".to_string())));
+        assert_eq!(context.len(), 3);
     }
 
     #[test]
-    fn test_create_indexed_context() {
+    fn test_update_indexed_context() {
+        let mut context = HashMap::new();
+        let mut index_keys = Vec::new();
         let record = csv::StringRecord::from(vec!["Alice", "25", "NYC"]);
-        
-        let context = create_indexed_context(&record);
-        
+        update_indexed_context(&mut context, &record, false, &mut index_keys, false);
+
         assert_eq!(context.get("0"), Some(&Value::String("Alice".to_string())));
         assert_eq!(context.get("1"), Some(&Value::String("25".to_string())));
         assert_eq!(context.get("2"), Some(&Value::String("NYC".to_string())));
     }
 
+    #[test]
+    fn test_update_indexed_context_drops_stale_indices_from_shorter_row() {
+        let mut context = HashMap::new();
+        let mut index_keys = Vec::new();
+        let first = csv::StringRecord::from(vec!["a", "b", "c"]);
+        update_indexed_context(&mut context, &first, false, &mut index_keys, true);
+
+        let second = csv::StringRecord::from(vec!["x", "y"]);
+        update_indexed_context(&mut context, &second, false, &mut index_keys, true);
+
+        assert_eq!(context.get("0"), Some(&Value::String("x".to_string())));
+        assert_eq!(context.get("1"), Some(&Value::String("y".to_string())));
+        assert_eq!(context.get("2"), None);
+    }
+
+    #[test]
+    fn test_update_indexed_context_reuses_index_keys_across_rows() {
+        let mut context = HashMap::new();
+        let mut index_keys = Vec::new();
+        let first = csv::StringRecord::from(vec!["a", "b"]);
+        update_indexed_context(&mut context, &first, false, &mut index_keys, true);
+        assert_eq!(index_keys, vec!["0".to_string(), "1".to_string()]);
+
+        // A wider row grows the cache, a narrower one doesn't shrink it.
+        let second = csv::StringRecord::from(vec!["x", "y", "z"]);
+        update_indexed_context(&mut context, &second, true, &mut index_keys, true);
+        assert_eq!(
+            index_keys,
+            vec!["0".to_string(), "1".to_string(), "2".to_string()]
+        );
+        assert_eq!(context.get("2"), Some(&Value::String("z".to_string())));
+
+        let third = csv::StringRecord::from(vec!["p", "q"]);
+        update_indexed_context(&mut context, &third, true, &mut index_keys, true);
+        assert_eq!(index_keys.len(), 3);
+        assert_eq!(context.get("2"), None);
+    }
+
+    #[test]
+    fn test_update_indexed_context_keeps_stale_index_without_flexible() {
+        // Without `--flexible`, csv::Reader enforces a constant field count,
+        // so a shorter row can't actually occur; this pins down that the
+        // retain pass is skipped (and therefore never clears a stale index)
+        // when the flag isn't set, which is exactly what keeps it off the
+        // hot path for well-formed CSVs.
+        let mut context = HashMap::new();
+        let mut index_keys = Vec::new();
+        let first = csv::StringRecord::from(vec!["a", "b", "c"]);
+        update_indexed_context(&mut context, &first, false, &mut index_keys, false);
+
+        let second = csv::StringRecord::from(vec!["x", "y"]);
+        update_indexed_context(&mut context, &second, false, &mut index_keys, false);
+
+        assert_eq!(context.get("0"), Some(&Value::String("x".to_string())));
+        assert_eq!(context.get("1"), Some(&Value::String("y".to_string())));
+        assert_eq!(context.get("2"), Some(&Value::String("c".to_string())));
+    }
+
+    #[test]
+    fn test_update_named_context_with_infer_types() {
+        let headers = csv::StringRecord::from(vec!["name", "age", "price", "active", "note"]);
+        let record = csv::StringRecord::from(vec!["Alice", "25", "19.99", "true", ""]);
+        let mut context = HashMap::new();
+        update_named_context(&mut context, &headers, &record, true);
+
+        assert_eq!(
+            context.get("name"),
+            Some(&Value::String("Alice".to_string()))
+        );
+        assert_eq!(context.get("age"), Some(&Value::Number(25.into())));
+        assert_eq!(
+            context.get("price"),
+            Some(&Value::Number(serde_json::Number::from_f64(19.99).unwrap()))
+        );
+        assert_eq!(context.get("active"), Some(&Value::Bool(true)));
+        assert_eq!(context.get("note"), Some(&Value::String(String::new())));
+    }
+
+    #[test]
+    fn test_infer_value_variants() {
+        assert_eq!(infer_value("42"), Value::Number(42.into()));
+        assert_eq!(
+            infer_value("2.5"),
+            Value::Number(serde_json::Number::from_f64(2.5).unwrap())
+        );
+        assert_eq!(infer_value("true"), Value::Bool(true));
+        assert_eq!(infer_value("false"), Value::Bool(false));
+        assert_eq!(infer_value("hello"), Value::String("hello".to_string()));
+        assert_eq!(infer_value(""), Value::String(String::new()));
+    }
+
+    #[test]
+    fn test_infer_value_preserves_leading_zeros() {
+        assert_eq!(infer_value("007"), Value::String("007".to_string()));
+        assert_eq!(infer_value("-007"), Value::String("-007".to_string()));
+        assert_eq!(infer_value("0"), Value::Number(0.into()));
+        assert_eq!(infer_value("10"), Value::Number(10.into()));
+        assert_eq!(
+            infer_value("0.5"),
+            Value::Number(serde_json::Number::from_f64(0.5).unwrap())
+        );
+    }
+
     #[test]
     fn test_csv_processor_new_valid_template() {
-        let processor = CsvProcessor::new("echo {{row.name}}", true);
+        let processor = CsvProcessor::new(&test_args("echo {{row.name}}", true, 1));
         assert!(processor.is_ok());
     }
 
     #[test]
     fn test_csv_processor_new_invalid_template() {
-        let processor = CsvProcessor::new("echo {{row.name", true);
+        let processor = CsvProcessor::new(&test_args("echo {{row.name", true, 1));
         assert!(processor.is_err());
     }
 
@@ -191,10 +1063,10 @@ mod tests {
     fn test_process_csv_with_headers() {
         let csv_data = "name,age\nAlice,25\nBob,30";
         let cursor = Cursor::new(csv_data);
-        
-        let processor = CsvProcessor::new("echo Hello {{row.name}}", true).unwrap();
+
+        let processor = CsvProcessor::new(&test_args("echo Hello {{row.name}}", true, 1)).unwrap();
         let result = processor.process_reader(cursor);
-        
+
         assert!(result.is_ok());
     }
 
@@ -202,10 +1074,10 @@ mod tests {
     fn test_process_csv_without_headers() {
         let csv_data = "Alice,25\nBob,30";
         let cursor = Cursor::new(csv_data);
-        
-        let processor = CsvProcessor::new("echo Hello {{row['0']}}", false).unwrap();
+
+        let processor = CsvProcessor::new(&test_args("echo Hello {{row['0']}}", false, 1)).unwrap();
         let result = processor.process_reader(cursor);
-        
+
         assert!(result.is_ok());
     }
 
@@ -213,21 +1085,26 @@ mod tests {
     fn test_process_empty_csv() {
         let csv_data = "";
         let cursor = Cursor::new(csv_data);
-        
-        let processor = CsvProcessor::new("echo {{row['0']}}", false).unwrap();
+
+        let processor = CsvProcessor::new(&test_args("echo {{row['0']}}", false, 1)).unwrap();
         let result = processor.process_reader(cursor);
-        
+
         assert!(result.is_ok());
     }
 
-    #[test] 
+    #[test]
     fn test_process_csv_with_missing_fields() {
         let csv_data = "name,age\nAlice,25\nBob,";
         let cursor = Cursor::new(csv_data);
-        
-        let processor = CsvProcessor::new("echo Hello {{row.name}} age {{row.age}}", true).unwrap();
+
+        let processor = CsvProcessor::new(&test_args(
+            "echo Hello {{row.name}} age {{row.age}}",
+            true,
+            1,
+        ))
+        .unwrap();
         let result = processor.process_reader(cursor);
-        
+
         assert!(result.is_ok());
     }
 
@@ -237,19 +1114,19 @@ mod tests {
         writeln!(temp_file, "name,age")?;
         writeln!(temp_file, "Alice,25")?;
         writeln!(temp_file, "Bob,30")?;
-        
-        let processor = CsvProcessor::new("echo Hello {{row.name}}", true)?;
+
+        let processor = CsvProcessor::new(&test_args("echo Hello {{row.name}}", true, 1))?;
         let result = processor.process_file(temp_file.path());
-        
+
         assert!(result.is_ok());
         Ok(())
     }
 
     #[test]
     fn test_process_nonexistent_file() {
-        let processor = CsvProcessor::new("echo {{row['0']}}", false).unwrap();
+        let processor = CsvProcessor::new(&test_args("echo {{row['0']}}", false, 1)).unwrap();
         let result = processor.process_file("/nonexistent/file.csv");
-        
+
         assert!(result.is_err());
     }
 
@@ -257,10 +1134,10 @@ mod tests {
     fn test_template_rendering_with_special_characters() {
         let csv_data = "message\nHello World\nquoted text";
         let cursor = Cursor::new(csv_data);
-        
-        let processor = CsvProcessor::new("echo '{{row.message}}'", true).unwrap();
+
+        let processor = CsvProcessor::new(&test_args("echo '{{row.message}}'", true, 1)).unwrap();
         let result = processor.process_reader(cursor);
-        
+
         assert!(result.is_ok());
     }
 
@@ -268,13 +1145,351 @@ mod tests {
     fn test_multiple_columns_template() {
         let csv_data = "first,last,age\nJohn,Doe,30\nJane,Smith,25";
         let cursor = Cursor::new(csv_data);
-        
-        let processor = CsvProcessor::new("echo {{row.first}} {{row.last}} is {{row.age}} years old", true).unwrap();
+
+        let processor = CsvProcessor::new(&test_args(
+            "echo {{row.first}} {{row.last}} is {{row.age}} years old",
+            true,
+            1,
+        ))
+        .unwrap();
+        let result = processor.process_reader(cursor);
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_process_csv_with_multiple_jobs() {
+        let csv_data = "name,age\nAlice,25\nBob,30\nCarol,40\nDan,50";
+        let cursor = Cursor::new(csv_data);
+
+        let processor = CsvProcessor::new(&test_args("echo Hello {{row.name}}", true, 4)).unwrap();
         let result = processor.process_reader(cursor);
-        
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_process_csv_with_multiple_jobs_propagates_failure() {
+        let csv_data = "name\nAlice\nBob";
+        let cursor = Cursor::new(csv_data);
+
+        let processor = CsvProcessor::new(&test_args("exit 1", true, 2)).unwrap();
+        let result = processor.process_reader(cursor);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_csv_processor_new_zero_jobs_resolves_to_cpu_count() {
+        let processor = CsvProcessor::new(&test_args("echo {{row.name}}", true, 0)).unwrap();
+        assert!(processor.jobs >= 1);
+    }
+
+    #[test]
+    fn test_process_tsv_with_custom_delimiter() {
+        let tsv_data = "name\tage\nAlice\t25\nBob\t30";
+        let cursor = Cursor::new(tsv_data);
+
+        let mut args = test_args("echo Hello {{row.name}}", true, 1);
+        args.delimiter = Some("\\t".to_string());
+
+        let processor = CsvProcessor::new(&args).unwrap();
+        let result = processor.process_reader(cursor);
+
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_process_csv_with_trim_and_flexible() {
+        let csv_data = "name, age\n Alice , 25\nBob";
+        let cursor = Cursor::new(csv_data);
+
+        let mut args = test_args("echo Hello {{row.name}}", true, 1);
+        args.trim = true;
+        args.flexible = true;
+
+        let processor = CsvProcessor::new(&args).unwrap();
+        let result = processor.process_reader(cursor);
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_process_csv_with_infer_types_arithmetic() {
+        let csv_data = "name,age\nAlice,25";
+        let cursor = Cursor::new(csv_data);
+
+        let mut args = test_args("echo {{row.name}} is {{row.age + 1}}", true, 1);
+        args.infer_types = true;
+
+        let processor = CsvProcessor::new(&args).unwrap();
+        let result = processor.process_reader(cursor);
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_parse_dialect_char_rejects_multi_character_value() {
+        let result = parse_dialect_char("--delimiter", "ab");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_dialect_char_accepts_tab_escape() {
+        let result = parse_dialect_char("--delimiter", "\\t").unwrap();
+        assert_eq!(result, b'\t');
+    }
+
+    #[test]
+    fn test_process_csv_dry_run_does_not_execute_command() {
+        let csv_data = "name\nAlice";
+        let cursor = Cursor::new(csv_data);
+
+        let mut args = test_args("touch /nonexistent/should-not-run-{{row.name}}", true, 1);
+        args.dry_run = true;
+
+        let processor = CsvProcessor::new(&args).unwrap();
+        let result = processor.process_reader(cursor);
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_process_csv_to_output_csv() -> Result<()> {
+        let csv_data = "name,age\nAlice,25\nBob,30";
+        let cursor = Cursor::new(csv_data);
+
+        let output_file = NamedTempFile::new()?;
+        let mut args = test_args("echo {{row.name}}", true, 1);
+        args.output_csv = Some(output_file.path().to_string_lossy().to_string());
+
+        let processor = CsvProcessor::new(&args)?;
+        processor.process_reader(cursor)?;
+
+        let contents = std::fs::read_to_string(output_file.path())?;
+        let mut lines = contents.lines();
+        assert_eq!(lines.next(), Some("name,age,command_stdout"));
+        assert_eq!(lines.next(), Some("Alice,25,Alice"));
+        assert_eq!(lines.next(), Some("Bob,30,Bob"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_process_csv_to_output_csv_with_flexible_ragged_rows() -> Result<()> {
+        let csv_data = "a,b,c\nx,y\np,q,r,s";
+        let cursor = Cursor::new(csv_data);
+
+        let output_file = NamedTempFile::new()?;
+        let mut args = test_args("echo {{row['0']}}", false, 1);
+        args.flexible = true;
+        args.output_csv = Some(output_file.path().to_string_lossy().to_string());
+
+        let processor = CsvProcessor::new(&args)?;
+        processor.process_reader(cursor)?;
+
+        let contents = std::fs::read_to_string(output_file.path())?;
+        let mut lines = contents.lines();
+        assert_eq!(lines.next(), Some("a,b,c,a"));
+        assert_eq!(lines.next(), Some("x,y,x"));
+        assert_eq!(lines.next(), Some("p,q,r,s,p"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_process_csv_to_output_csv_dry_run_leaves_stdout_column_empty() -> Result<()> {
+        let csv_data = "name\nAlice";
+        let cursor = Cursor::new(csv_data);
+
+        let output_file = NamedTempFile::new()?;
+        let mut args = test_args("echo {{row.name}}", true, 1);
+        args.dry_run = true;
+        args.output_csv = Some(output_file.path().to_string_lossy().to_string());
+
+        let processor = CsvProcessor::new(&args)?;
+        processor.process_reader(cursor)?;
+
+        let contents = std::fs::read_to_string(output_file.path())?;
+        let mut lines = contents.lines();
+        assert_eq!(lines.next(), Some("name,command_stdout"));
+        assert_eq!(lines.next(), Some("Alice,"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_process_csv_continue_on_error_runs_every_row_then_reports_failure() {
+        let dir = tempfile::tempdir().unwrap();
+        let first = dir.path().join("first");
+        let third = dir.path().join("third");
+
+        let csv_data = format!(
+            "cmd\ntouch {}\nfalse\ntouch {}",
+            first.display(),
+            third.display()
+        );
+        let cursor = Cursor::new(csv_data);
+
+        let mut args = test_args("{{row.cmd}}", true, 1);
+        args.continue_on_error = true;
+
+        let processor = CsvProcessor::new(&args).unwrap();
+        let result = processor.process_reader(cursor);
+
+        assert!(result.is_err());
+        assert!(first.exists());
+        assert!(third.exists());
+    }
+
+    #[test]
+    fn test_process_csv_without_continue_on_error_stops_at_first_failure() {
+        let dir = tempfile::tempdir().unwrap();
+        let third = dir.path().join("third");
+
+        let csv_data = format!("cmd\ntrue\nfalse\ntouch {}", third.display());
+        let cursor = Cursor::new(csv_data);
+
+        let processor = CsvProcessor::new(&test_args("{{row.cmd}}", true, 1)).unwrap();
+        let result = processor.process_reader(cursor);
+
+        assert!(result.is_err());
+        assert!(!third.exists());
+    }
+
+    #[test]
+    fn test_process_csv_with_multiple_jobs_without_continue_on_error_skips_summary() {
+        let csv_data = "cmd\nfalse";
+        let cursor = Cursor::new(csv_data);
+
+        let processor = CsvProcessor::new(&test_args("{{row.cmd}}", true, 2)).unwrap();
+        let result = processor.process_reader(cursor);
+
+        let err = result.unwrap_err();
+        assert!(!format!("{:#}", err).contains("row(s) failed"));
+    }
+
+    #[test]
+    fn test_process_csv_with_multiple_jobs_reports_earliest_row_on_concurrent_failures() {
+        // Row 0's command fails slower than row 1's, so row 1 is likely to
+        // be the one that completes (and sets `stop`) first; the reported
+        // failure should still be row 0's, the earliest row in the file,
+        // not whichever happened to finish first.
+        let csv_data = "cmd\nsleep 0.2 && false\nfalse";
+        let cursor = Cursor::new(csv_data);
+
+        let processor = CsvProcessor::new(&test_args("{{row.cmd}}", true, 2)).unwrap();
+        let result = processor.process_reader(cursor);
+
+        let err = format!("{:#}", result.unwrap_err());
+        assert!(
+            err.contains("row 0"),
+            "expected row 0's failure, got: {}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_process_csv_with_multiple_jobs_reports_earliest_failure_over_later_read_error() {
+        // Row 0's command is still running (and will fail) when row 2's
+        // malformed record trips a read error on the main thread; the
+        // earlier command failure should still win even though the read
+        // error is detected first.
+        let csv_data = "cmd\nsleep 0.3 && false\ntrue\na,b";
+        let cursor = Cursor::new(csv_data);
+
+        let processor = CsvProcessor::new(&test_args("{{row.cmd}}", true, 2)).unwrap();
+        let result = processor.process_reader(cursor);
+
+        let err = format!("{:#}", result.unwrap_err());
+        assert!(
+            err.contains("row 0"),
+            "expected row 0's failure, got: {}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_process_csv_continue_on_error_continues_past_malformed_row() {
+        // A ragged row (without --flexible) is a *read* error, not a command
+        // failure. --continue-on-error should record it by row index and
+        // keep going, same as a failing command.
+        let dir = tempfile::tempdir().unwrap();
+        let first = dir.path().join("first");
+        let third = dir.path().join("third");
+
+        let csv_data = format!(
+            "cmd\ntouch {}\na,b\ntouch {}",
+            first.display(),
+            third.display()
+        );
+        let cursor = Cursor::new(csv_data);
+
+        let mut args = test_args("{{row.cmd}}", true, 1);
+        args.continue_on_error = true;
+
+        let processor = CsvProcessor::new(&args).unwrap();
+        let result = processor.process_reader(cursor);
+
+        let err = format!("{:#}", result.unwrap_err());
+        assert!(first.exists());
+        assert!(third.exists());
+        assert!(err.contains("1 of 3 row(s) failed"), "got: {}", err);
+        assert!(err.contains("row 1"), "got: {}", err);
+    }
+
+    #[test]
+    fn test_process_csv_continue_on_error_with_output_csv_writes_placeholder_for_read_error() {
+        // A read error (ragged row without --flexible) must still produce a
+        // row in --output-csv, same as the render-failure placeholder, so
+        // output rows stay 1:1 with input rows.
+        let csv_data = "cmd\necho first\na,b\necho third";
+        let cursor = Cursor::new(csv_data);
+
+        let output_file = NamedTempFile::new().unwrap();
+        let mut args = test_args("{{row.cmd}}", true, 1);
+        args.continue_on_error = true;
+        args.output_csv = Some(output_file.path().to_string_lossy().to_string());
+
+        let processor = CsvProcessor::new(&args).unwrap();
+        let result = processor.process_reader(cursor);
+
+        let err = format!("{:#}", result.unwrap_err());
+        assert!(err.contains("1 of 3 row(s) failed"), "got: {}", err);
+
+        let contents = std::fs::read_to_string(output_file.path()).unwrap();
+        let mut lines = contents.lines();
+        assert_eq!(lines.next(), Some("cmd,command_stdout"));
+        assert_eq!(lines.next(), Some("echo first,first"));
+        assert_eq!(lines.next(), Some(","));
+        assert_eq!(lines.next(), Some("echo third,third"));
+        assert_eq!(lines.next(), None);
+    }
+
+    #[test]
+    fn test_process_csv_continue_on_error_with_multiple_jobs_runs_every_row() {
+        let dir = tempfile::tempdir().unwrap();
+        let first = dir.path().join("first");
+        let third = dir.path().join("third");
+
+        let csv_data = format!(
+            "cmd\ntouch {}\nfalse\ntouch {}",
+            first.display(),
+            third.display()
+        );
+        let cursor = Cursor::new(csv_data);
+
+        let mut args = test_args("{{row.cmd}}", true, 2);
+        args.continue_on_error = true;
+
+        let processor = CsvProcessor::new(&args).unwrap();
+        let result = processor.process_reader(cursor);
+
+        assert!(result.is_err());
+        assert!(first.exists());
+        assert!(third.exists());
+    }
+
     mod integration_tests {
         use super::*;
         use assert_cmd::Command;
@@ -286,15 +1501,14 @@ mod tests {
             let mut temp_file = NamedTempFile::new()?;
             writeln!(temp_file, "name,age")?;
             writeln!(temp_file, "Alice,25")?;
-            
+
             let mut cmd = Command::cargo_bin("csvargs")?;
-            cmd.arg("echo test-{{row.name}}")
-                .arg(temp_file.path());
-            
+            cmd.arg("echo test-{{row.name}}").arg(temp_file.path());
+
             cmd.assert()
                 .success()
                 .stdout(predicate::str::contains("test-Alice"));
-            
+
             Ok(())
         }
 
@@ -302,28 +1516,189 @@ mod tests {
         fn test_cli_without_headers() -> Result<()> {
             let mut temp_file = NamedTempFile::new()?;
             writeln!(temp_file, "Alice,25")?;
-            
+
             let mut cmd = Command::cargo_bin("csvargs")?;
             cmd.arg("--no-header")
                 .arg("echo test-{{row['0']}}")
                 .arg(temp_file.path());
-            
+
             cmd.assert()
                 .success()
                 .stdout(predicate::str::contains("test-Alice"));
-            
+
             Ok(())
         }
 
         #[test]
-        fn test_cli_no_files() -> Result<()> {
+        fn test_cli_with_jobs_flag() -> Result<()> {
+            let mut temp_file = NamedTempFile::new()?;
+            writeln!(temp_file, "name,age")?;
+            writeln!(temp_file, "Alice,25")?;
+            writeln!(temp_file, "Bob,30")?;
+
             let mut cmd = Command::cargo_bin("csvargs")?;
-            cmd.arg("echo {{row['0']}}");
-            
+            cmd.arg("--jobs")
+                .arg("2")
+                .arg("echo test-{{row.name}}")
+                .arg(temp_file.path());
+
+            cmd.assert()
+                .success()
+                .stdout(predicate::str::contains("test-Alice"))
+                .stdout(predicate::str::contains("test-Bob"));
+
+            Ok(())
+        }
+
+        #[test]
+        fn test_cli_with_custom_delimiter() -> Result<()> {
+            let mut temp_file = NamedTempFile::new()?;
+            writeln!(temp_file, "name;age")?;
+            writeln!(temp_file, "Alice;25")?;
+
+            let mut cmd = Command::cargo_bin("csvargs")?;
+            cmd.arg("--delimiter")
+                .arg(";")
+                .arg("echo test-{{row.name}}")
+                .arg(temp_file.path());
+
+            cmd.assert()
+                .success()
+                .stdout(predicate::str::contains("test-Alice"));
+
+            Ok(())
+        }
+
+        #[test]
+        fn test_cli_with_flexible_no_header_does_not_leak_stale_fields() -> Result<()> {
+            let mut temp_file = NamedTempFile::new()?;
+            writeln!(temp_file, "a,b,c")?;
+            writeln!(temp_file, "x,y")?;
+
+            let mut cmd = Command::cargo_bin("csvargs")?;
+            cmd.arg("--no-header")
+                .arg("--flexible")
+                .arg("--dry-run")
+                .arg("{{row['0']}} {{row['1']}} {{row['2']}}")
+                .arg(temp_file.path());
+
+            cmd.assert()
+                .success()
+                .stdout(predicate::str::contains("Would execute for row 0: a b c"))
+                .stdout(predicate::str::contains("Would execute for row 1: x y"))
+                .stdout(predicate::str::contains("x y c").not());
+
+            Ok(())
+        }
+
+        #[test]
+        fn test_cli_with_infer_types() -> Result<()> {
+            let mut temp_file = NamedTempFile::new()?;
+            writeln!(temp_file, "name,age")?;
+            writeln!(temp_file, "Alice,25")?;
+
+            let mut cmd = Command::cargo_bin("csvargs")?;
+            cmd.arg("--infer-types")
+                .arg("echo {{row.name}} next-year {{row.age + 1}}")
+                .arg(temp_file.path());
+
+            cmd.assert()
+                .success()
+                .stdout(predicate::str::contains("Alice next-year 26"));
+
+            Ok(())
+        }
+
+        #[test]
+        fn test_cli_with_dry_run() -> Result<()> {
+            let mut temp_file = NamedTempFile::new()?;
+            writeln!(temp_file, "name")?;
+            writeln!(temp_file, "Alice")?;
+
+            let mut cmd = Command::cargo_bin("csvargs")?;
+            cmd.arg("--dry-run")
+                .arg("echo {{row.name}}")
+                .arg(temp_file.path());
+
+            cmd.assert().success().stdout(predicate::str::contains(
+                "Would execute for row 0: echo Alice",
+            ));
+
+            Ok(())
+        }
+
+        #[test]
+        fn test_cli_with_output_csv() -> Result<()> {
+            let mut temp_file = NamedTempFile::new()?;
+            writeln!(temp_file, "name")?;
+            writeln!(temp_file, "Alice")?;
+
+            let output_file = NamedTempFile::new()?;
+
+            let mut cmd = Command::cargo_bin("csvargs")?;
+            cmd.arg("--output-csv")
+                .arg(output_file.path())
+                .arg("echo {{row.name}}")
+                .arg(temp_file.path());
+
+            cmd.assert().success();
+
+            let contents = std::fs::read_to_string(output_file.path())?;
+            assert!(contents.contains("name,command_stdout"));
+            assert!(contents.contains("Alice,Alice"));
+
+            Ok(())
+        }
+
+        #[test]
+        fn test_cli_with_continue_on_error() -> Result<()> {
+            let mut temp_file = NamedTempFile::new()?;
+            writeln!(temp_file, "cmd")?;
+            writeln!(temp_file, "echo first")?;
+            writeln!(temp_file, "false")?;
+            writeln!(temp_file, "echo third")?;
+
+            let mut cmd = Command::cargo_bin("csvargs")?;
+            cmd.arg("--continue-on-error")
+                .arg("{{row.cmd}}")
+                .arg(temp_file.path());
+
+            cmd.assert()
+                .failure()
+                .stdout(predicate::str::contains("first").and(predicate::str::contains("third")))
+                .stderr(predicate::str::contains("1 of 3 row(s) failed"));
+
+            Ok(())
+        }
+
+        #[test]
+        fn test_cli_jobs_without_continue_on_error_skips_failure_summary() -> Result<()> {
+            let mut temp_file = NamedTempFile::new()?;
+            writeln!(temp_file, "cmd")?;
+            writeln!(temp_file, "false")?;
+
+            let mut cmd = Command::cargo_bin("csvargs")?;
+            cmd.arg("--jobs")
+                .arg("2")
+                .arg("{{row.cmd}}")
+                .arg(temp_file.path());
+
             cmd.assert()
                 .failure()
-                .stderr(predicate::str::contains("At least one CSV file must be provided"));
-            
+                .stderr(predicate::str::contains("row(s) failed").not());
+
+            Ok(())
+        }
+
+        #[test]
+        fn test_cli_no_files() -> Result<()> {
+            let mut cmd = Command::cargo_bin("csvargs")?;
+            cmd.arg("echo {{row['0']}}");
+
+            cmd.assert().failure().stderr(predicate::str::contains(
+                "At least one CSV file must be provided",
+            ));
+
             Ok(())
         }
 
@@ -331,29 +1706,27 @@ mod tests {
         fn test_cli_invalid_template() -> Result<()> {
             let mut temp_file = NamedTempFile::new()?;
             writeln!(temp_file, "Alice,25")?;
-            
+
             let mut cmd = Command::cargo_bin("csvargs")?;
-            cmd.arg("echo {{row['0'")
-                .arg(temp_file.path());
-            
+            cmd.arg("echo {{row['0'").arg(temp_file.path());
+
             cmd.assert()
                 .failure()
                 .stderr(predicate::str::contains("Failed to parse template"));
-            
+
             Ok(())
         }
 
         #[test]
         fn test_cli_nonexistent_file() -> Result<()> {
             let mut cmd = Command::cargo_bin("csvargs")?;
-            cmd.arg("echo {{row['0']}}")
-                .arg("/nonexistent/file.csv");
-            
+            cmd.arg("echo {{row['0']}}").arg("/nonexistent/file.csv");
+
             cmd.assert()
                 .failure()
                 .stderr(predicate::str::contains("Failed to open file"));
-            
+
             Ok(())
         }
     }
-}
\ No newline at end of file
+}